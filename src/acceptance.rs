@@ -0,0 +1,108 @@
+use super::MatchType;
+
+/// Criteria that matches have to meet to be accepted. Matches that fail any
+/// of them are downgraded to NoMatch, and the reason of the rejection is
+/// added to the output. Criteria that are not set accept all matches.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Acceptance {
+    /// Maximum edit distance between the name-string and the matched name.
+    pub max_edit_distance: Option<i64>,
+    /// Maximum edit distance between stemmed versions of the name-string and
+    /// the matched name.
+    pub max_stem_edit_distance: Option<i64>,
+    /// Minimum score of a match. Matches without a score are rejected.
+    pub min_score: Option<f64>,
+    /// Match types that are accepted.
+    pub match_types: Option<Vec<MatchType>>,
+}
+
+impl Acceptance {
+    /// Checks properties of a match against the criteria. Returns the reason
+    /// of the rejection if the match does not meet them.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use gnverify::acceptance::Acceptance;
+    /// use gnverify::MatchType;
+    ///
+    /// let acceptance = Acceptance {
+    ///     max_edit_distance: Some(1),
+    ///     ..Default::default()
+    /// };
+    /// assert!(acceptance.check(&MatchType::Fuzzy, 1, 1, Some(0.9)).is_ok());
+    /// assert_eq!(
+    ///     acceptance.check(&MatchType::Fuzzy, 3, 2, Some(0.9)),
+    ///     Err("edit distance 3 is larger than 1".to_owned())
+    /// );
+    /// ```
+    pub fn check(
+        &self,
+        match_type: &MatchType,
+        edit_distance: i64,
+        stem_edit_distance: i64,
+        score: Option<f64>,
+    ) -> Result<(), String> {
+        if let Some(types) = &self.match_types {
+            if !types.contains(match_type) {
+                return Err(format!("match type {} is not accepted", match_type));
+            }
+        }
+        if let Some(max) = self.max_edit_distance {
+            if edit_distance > max {
+                return Err(format!(
+                    "edit distance {} is larger than {}",
+                    edit_distance, max
+                ));
+            }
+        }
+        if let Some(max) = self.max_stem_edit_distance {
+            if stem_edit_distance > max {
+                return Err(format!(
+                    "stem edit distance {} is larger than {}",
+                    stem_edit_distance, max
+                ));
+            }
+        }
+        if let Some(min) = self.min_score {
+            match score {
+                Some(score) if score >= min => (),
+                Some(score) => {
+                    return Err(format!("score {} is smaller than {}", score, min));
+                }
+                None => return Err("score is unknown".to_owned()),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn acceptance_check() {
+    let acceptance = Acceptance {
+        max_stem_edit_distance: Some(0),
+        min_score: Some(0.9),
+        match_types: Some(vec![MatchType::Exact, MatchType::Fuzzy]),
+        ..Default::default()
+    };
+    assert!(acceptance
+        .check(&MatchType::Exact, 0, 0, Some(0.95))
+        .is_ok());
+    assert_eq!(
+        acceptance.check(&MatchType::PartialExact, 0, 0, Some(0.95)),
+        Err("match type PartialExact is not accepted".to_owned())
+    );
+    assert!(acceptance
+        .check(&MatchType::Fuzzy, 1, 1, Some(0.95))
+        .is_err());
+    assert!(acceptance
+        .check(&MatchType::Exact, 0, 0, Some(0.5))
+        .is_err());
+    assert_eq!(
+        acceptance.check(&MatchType::Exact, 0, 0, None),
+        Err("score is unknown".to_owned())
+    );
+    assert!(Acceptance::default()
+        .check(&MatchType::PartialFuzzy, 3, 3, None)
+        .is_ok());
+}
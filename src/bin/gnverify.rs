@@ -1,18 +1,36 @@
 use clap::crate_version;
 use crossbeam_channel::{bounded, Receiver, Sender};
-use gnverify::{Format, GNVerify};
-use log::{error, info};
+use gnverify::acceptance::Acceptance;
+use gnverify::cache::{self, Cache};
+use gnverify::dwca::{self, Archive};
+use gnverify::retry::RetryPolicy;
+use gnverify::{Delimiter, Format, GNVerify, MatchType, NameField};
+use log::{error, info, warn};
+use std::fmt;
 use std::fs::File;
-use std::io::{self, Read};
+use std::io::{self, BufWriter, Read, Write};
 use std::path;
 use std::process;
+use std::str;
 use std::thread;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use stderrlog::{self, Timestamp};
 
 #[macro_use]
 extern crate clap;
 
+const MAX_JOBS: usize = 100;
+const MAX_BATCH_SIZE: usize = 10_000;
+const SAMPLE_SIZE: u64 = 8_192;
+const MAX_CACHE_TTL: usize = 365;
+const DEFAULT_CACHE_TTL: usize = 7;
+const SECS_IN_DAY: u64 = 86_400;
+const MAX_TIMEOUT: u64 = 3_600;
+const MAX_RETRIES: u32 = 20;
+const MAX_RETRY_DELAY: u64 = 60_000;
+const MAX_RETRY_BACKOFF: f64 = 10.0;
+const MAX_EDIT_DISTANCE: i64 = 20;
+
 fn main() {
     stderrlog::new()
         .verbosity(2)
@@ -28,26 +46,147 @@ fn main() {
     if matches.is_present("preferred_only") {
         gnv.preferred_only();
     }
+    if matches.is_present("all_matches") {
+        gnv.all_matches();
+    }
+    if let Some(languages) = matches.value_of("vernaculars") {
+        gnv.vernaculars(parse_languages(languages));
+    }
+    if matches.is_present("ordered") {
+        gnv.ordered();
+    }
+    if matches.is_present("rank_columns") {
+        gnv.rank_columns();
+    }
+    if matches.is_present("score_columns") {
+        gnv.score_columns();
+    }
+    if matches.is_present("with_fields") {
+        gnv.with_fields();
+    }
     if let Some(format_str) = matches.value_of("format") {
         match Format::new(format_str) {
             Ok(format) => {
                 gnv.format(format);
             }
             Err(err) => {
-                error!("using default format {}: {}", gnv.format.to_string(), err);
+                error!("using default format {}: {}", gnv.format, err);
             }
         }
     }
-    if let Some(name_index) = matches.value_of("name_field") {
-        gnv.name_field(parse_name_index(name_index))
+    if let Some(delimiter_str) = matches.value_of("delimiter") {
+        match Delimiter::new(delimiter_str) {
+            Ok(delimiter) => gnv.delimiter(delimiter),
+            Err(err) => {
+                error!("{}", err);
+                process::exit(1);
+            }
+        }
+    }
+    if matches.is_present("header") {
+        gnv.header();
+    }
+    if let Some(name_field) = matches.value_of("name_field") {
+        gnv.name_field(parse_field(name_field, "name_field"));
+    }
+    if let Some(id_field) = matches.value_of("id_field") {
+        gnv.id_field(parse_field(id_field, "id_field"));
+    }
+    let by_header = |f: &NameField| matches!(f, NameField::Header(_));
+    if by_header(&gnv.name_field) || gnv.id_field.as_ref().is_some_and(by_header) {
+        gnv.header();
     }
     if let Some(srs) = matches.value_of("sources") {
         gnv.sources(parse_sources(srs));
     }
+    if let Some(srs) = matches.value_of("only_sources") {
+        gnv.only_sources(parse_sources(srs));
+    }
+    if let Some(jobs) = matches.value_of("jobs") {
+        gnv.jobs(parse_limited(jobs, "jobs", MAX_JOBS));
+    }
+    if let Some(batch_size) = matches.value_of("batch_size") {
+        gnv.batch_size(parse_limited(batch_size, "batch_size", MAX_BATCH_SIZE));
+    }
+    if let Some(url) = matches.value_of("url") {
+        gnv.url(url);
+    }
+    if let Some(timeout) = matches.value_of("connect_timeout") {
+        let timeout = parse_range(timeout, "connect_timeout", 0, MAX_TIMEOUT);
+        gnv.connect_timeout(Duration::from_secs(timeout));
+    }
+    if let Some(timeout) = matches.value_of("timeout") {
+        let timeout = parse_range(timeout, "timeout", 0, MAX_TIMEOUT);
+        gnv.timeout(Duration::from_secs(timeout));
+    }
+    if let Some(proxy) = matches.value_of("proxy") {
+        gnv.proxy(proxy);
+    }
+    if let Some(certs) = matches.values_of("ca_cert") {
+        for cert in certs {
+            gnv.root_certificate(cert);
+        }
+    }
+    let mut retry = RetryPolicy::default();
+    if let Some(retries) = matches.value_of("retries") {
+        retry.max_retries = parse_range(retries, "retries", 0, MAX_RETRIES);
+    }
+    if let Some(delay) = matches.value_of("retry_delay") {
+        let delay = parse_range(delay, "retry_delay", 0, MAX_RETRY_DELAY);
+        retry.initial_delay = Duration::from_millis(delay);
+    }
+    if let Some(backoff) = matches.value_of("retry_backoff") {
+        retry.backoff_factor = parse_range(backoff, "retry_backoff", 1.0, MAX_RETRY_BACKOFF);
+    }
+    if let Some(jitter) = matches.value_of("retry_jitter") {
+        retry.jitter = parse_range(jitter, "retry_jitter", 0.0, 1.0);
+    }
+    gnv.retry(retry);
+    let mut acceptance = Acceptance::default();
+    if let Some(dist) = matches.value_of("max_edit_distance") {
+        let dist = parse_range(dist, "max_edit_distance", 0, MAX_EDIT_DISTANCE);
+        acceptance.max_edit_distance = Some(dist);
+    }
+    if let Some(dist) = matches.value_of("max_stem_edit_distance") {
+        let dist = parse_range(dist, "max_stem_edit_distance", 0, MAX_EDIT_DISTANCE);
+        acceptance.max_stem_edit_distance = Some(dist);
+    }
+    if let Some(score) = matches.value_of("min_score") {
+        acceptance.min_score = Some(parse_range(score, "min_score", 0.0, 1.0));
+    }
+    if let Some(types) = matches.value_of("match_types") {
+        acceptance.match_types = Some(parse_match_types(types));
+    }
+    gnv.acceptance(acceptance);
+    if !matches.is_present("no_cache") {
+        let ttl = match matches.value_of("cache_ttl") {
+            Some(ttl) => parse_limited(ttl, "cache_ttl", MAX_CACHE_TTL),
+            None => DEFAULT_CACHE_TTL,
+        };
+        let dir = match matches.value_of("cache_dir") {
+            Some(dir) => Some(path::PathBuf::from(dir)),
+            None => cache::default_dir(),
+        };
+        if let Some(dir) = dir {
+            match Cache::new(dir, Duration::from_secs(ttl as u64 * SECS_IN_DAY)) {
+                Ok(c) => gnv.cache(c),
+                Err(err) => warn!("Working without cache: {:#}", err),
+            }
+        }
+    }
+    let output = matches.value_of("output");
     if let Some(ref input) = matches.value_of("INPUT") {
-        if path::Path::new(input).exists() {
+        if dwca::is_archive(input) {
+            match verify_dwca(gnv, input, create_writer(output)) {
+                Ok(_) => process::exit(0),
+                Err(err) => {
+                    println!("{:#?}", err);
+                    process::exit(1);
+                }
+            }
+        } else if path::Path::new(input).exists() {
             let f = File::open(input).unwrap();
-            match verify_file(gnv, f) {
+            match verify_file(gnv, f, create_writer(output)) {
                 Ok(_) => process::exit(0),
                 Err(err) => {
                     println!("{:#?}", err);
@@ -55,13 +194,23 @@ fn main() {
                 }
             }
         } else {
-            gnv.verify_and_format(&vec![gnverify::Input {
+            let outputs = gnv.verify(&[gnverify::Input {
                 id: None,
                 name: input.to_string(),
+                fields: None,
             }]);
+            let mut w = create_writer(output);
+            if let Err(err) = gnv
+                .format_outputs_to(&mut w, outputs, true)
+                .and_then(|_| Ok(w.flush()?))
+            {
+                error!("Cannot write output: {}", err);
+                process::exit(1);
+            }
+            log_cache_stats(&gnv);
         }
     } else if is_readable_stdin() {
-        match verify_file(gnv, io::stdin()) {
+        match verify_file(gnv, io::stdin(), create_writer(output)) {
             Ok(_) => process::exit(0),
             Err(err) => {
                 println!("{:#?}", err);
@@ -73,54 +222,145 @@ fn main() {
     }
 }
 
-fn verify_file<'a, R>(gnv: GNVerify, r: R) -> io::Result<()>
+fn create_writer(output: Option<&str>) -> Box<dyn Write + Send> {
+    match output {
+        Some(path) => match File::create(path) {
+            Ok(f) => Box::new(BufWriter::new(f)),
+            Err(err) => {
+                error!("Cannot create output file '{}': {}", path, err);
+                process::exit(1);
+            }
+        },
+        None => Box::new(io::stdout()),
+    }
+}
+
+fn verify_file<R>(gnv: GNVerify, mut r: R, w: Box<dyn Write + Send>) -> io::Result<()>
 where
     R: Read,
 {
-    let (in_s, in_r) = bounded(0);
-    let (out_s, out_r) = bounded(0);
-    let (done_s, done_r) = bounded::<bool>(0);
-    let gnv_clone1 = gnv.clone();
-    let gnv_clone2 = gnv.clone();
+    let mut sample: Vec<u8> = Vec::new();
+    if let Delimiter::Auto = gnv.delimiter {
+        (&mut r).take(SAMPLE_SIZE).read_to_end(&mut sample)?;
+    }
+    let delimiter = gnv.delimiter.to_byte(&sample);
     let batch_size = gnv.batch_size;
-    let name_field = gnv.name_field;
-    thread::spawn(move || gnv_clone1.verify_stream(in_r, out_s));
-    thread::spawn(move || process_outputs(gnv_clone2, out_r, done_s));
+    let name_field = gnv.name_field.clone();
+    let id_field = gnv.id_field.clone();
+    let with_fields = gnv.with_fields;
+    let (in_s, done_r) = start_verification(&gnv, w);
 
     let rdr = csv::ReaderBuilder::new()
-        .delimiter(b'\t')
-        .has_headers(false)
-        .from_reader(r);
+        .delimiter(delimiter)
+        .has_headers(gnv.header)
+        .from_reader(io::Cursor::new(sample).chain(r));
 
-    prepare_inputs(rdr, in_s, batch_size, name_field);
+    prepare_inputs(rdr, in_s, batch_size, name_field, id_field, with_fields);
     done_r.recv().unwrap();
+    log_cache_stats(&gnv);
     Ok(())
 }
 
+fn verify_dwca(mut gnv: GNVerify, path: &str, w: Box<dyn Write + Send>) -> anyhow::Result<()> {
+    let archive = Archive::open(path)?;
+    if let Some(i) = archive.core().id_index {
+        gnv.id_field(NameField::Index(i + 1));
+    }
+    info!(
+        "Verifying names from {} of Darwin Core Archive",
+        archive.core().location
+    );
+    let (in_s, done_r) = start_verification(&gnv, w);
+    archive.send_inputs(&gnv, in_s)?;
+    done_r.recv().unwrap();
+    log_cache_stats(&gnv);
+    Ok(())
+}
+
+fn log_cache_stats(gnv: &GNVerify) {
+    if let Some(stats) = gnv.cache_stats() {
+        info!("Cache: {}", stats);
+    }
+}
+
+/// Starts verification and output threads. Returns a channel for inputs and
+/// a channel that signals that all outputs are written.
+fn start_verification(
+    gnv: &GNVerify,
+    w: Box<dyn Write + Send>,
+) -> (Sender<Vec<gnverify::Input>>, Receiver<bool>) {
+    let (in_s, in_r) = bounded(0);
+    let (out_s, out_r) = bounded(0);
+    let (done_s, done_r) = bounded::<bool>(0);
+    let gnv_clone1 = gnv.clone();
+    let gnv_clone2 = gnv.clone();
+    thread::spawn(move || gnv_clone1.verify_stream(in_r, out_s));
+    thread::spawn(move || process_outputs(gnv_clone2, out_r, w, done_s));
+    (in_s, done_r)
+}
+
 fn process_outputs(
     gnv: gnverify::GNVerify,
     out_r: Receiver<Vec<gnverify::Output>>,
+    mut w: Box<dyn Write + Send>,
     done_s: Sender<bool>,
 ) {
+    if let Err(err) = write_outputs(&gnv, out_r, &mut w) {
+        error!("Cannot write output: {}", err);
+        process::exit(1);
+    }
+    done_s.send(true).unwrap();
+}
+
+fn write_outputs(
+    gnv: &gnverify::GNVerify,
+    out_r: Receiver<Vec<gnverify::Output>>,
+    w: &mut Box<dyn Write + Send>,
+) -> anyhow::Result<()> {
+    let json_array = matches!(gnv.format, Format::JSON);
+    if json_array {
+        w.write_all(b"[")?;
+    }
     let mut is_first = true;
     for outputs in out_r {
-        gnv.format_outputs(outputs, is_first);
+        if outputs.is_empty() {
+            continue;
+        }
+        if json_array {
+            gnv.format_json_items_to(w, outputs, is_first)?;
+        } else {
+            gnv.format_outputs_to(w, outputs, is_first)?;
+        }
         is_first = false;
     }
-    done_s.send(true).unwrap();
+    if json_array {
+        w.write_all(b"\n]\n")?;
+    }
+    w.flush()?;
+    Ok(())
 }
 
 fn prepare_inputs<R>(
-    rdr: csv::Reader<R>,
+    mut rdr: csv::Reader<R>,
     in_s: Sender<Vec<gnverify::Input>>,
     batch_size: usize,
-    name_field: i64,
+    name_field: NameField,
+    id_field: Option<NameField>,
+    with_fields: bool,
 ) where
     R: Read,
 {
     let mut inputs: Vec<gnverify::Input> = Vec::with_capacity(batch_size);
     let time_start = Instant::now();
     let mut good_rows = 0;
+    let mut headers: Vec<String> = Vec::new();
+    if rdr.has_headers() {
+        if let Ok(h) = rdr.headers() {
+            headers = h.iter().map(|f| f.to_owned()).collect();
+        }
+    }
+    let name_idx = field_position(&name_field, &headers);
+    let id_idx = id_field.map(|f| field_position(&f, &headers));
 
     for (i, result) in rdr.into_records().enumerate() {
         if inputs.len() == batch_size {
@@ -137,11 +377,23 @@ fn prepare_inputs<R>(
                 error!("Too many bad rows. Make sure you set name_field to the right number");
                 process::exit(1);
             }
-            if record.len() as i64 >= name_field {
+            if record.len() > name_idx {
                 good_rows += 1;
+                let id = id_idx.map(|i| record.get(i).unwrap_or("").to_owned());
+                let mut fields = None;
+                if with_fields {
+                    fields = Some(gnverify::Fields(
+                        record
+                            .iter()
+                            .enumerate()
+                            .map(|(i, v)| (field_name(&headers, i), v.to_owned()))
+                            .collect(),
+                    ));
+                }
                 inputs.push(gnverify::Input {
-                    id: None,
-                    name: record[name_field as usize - 1].to_owned(),
+                    id,
+                    name: record[name_idx].to_owned(),
+                    fields,
                 });
             };
         };
@@ -150,23 +402,82 @@ fn prepare_inputs<R>(
     drop(in_s);
 }
 
-fn parse_name_index(index_str: &str) -> i64 {
-    if let Ok(name_index) = index_str.trim().parse::<i64>() {
-        match name_index {
-            i if name_index > 0 => return i,
+fn field_name(headers: &[String], i: usize) -> String {
+    match headers.get(i) {
+        Some(h) if !h.is_empty() => h.to_owned(),
+        _ => format!("Field{}", i + 1),
+    }
+}
+
+fn parse_field(field_str: &str, option: &str) -> NameField {
+    match NameField::new(field_str) {
+        Ok(field) => field,
+        Err(err) => {
+            error!("Cannot set {}: {}", option, err);
+            process::exit(1);
+        }
+    }
+}
+
+fn field_position(field: &NameField, headers: &[String]) -> usize {
+    match field.position(headers) {
+        Ok(i) => i,
+        Err(err) => {
+            error!("{}", err);
+            process::exit(1);
+        }
+    }
+}
+
+fn parse_limited(value_str: &str, option: &str, max: usize) -> usize {
+    parse_range(value_str, option, 1, max)
+}
+
+fn parse_range<T>(value_str: &str, option: &str, min: T, max: T) -> T
+where
+    T: str::FromStr + PartialOrd + fmt::Display,
+{
+    if let Ok(value) = value_str.trim().parse::<T>() {
+        match value {
+            v if v >= min && v <= max => return v,
             _ => {
-                error!("Enter number 1 or larger for name_field");
+                error!("Enter number from {} to {} for {}", min, max, option);
                 process::exit(1);
             }
         }
     }
     error!(
-        "Cannot parse name_field index '{}', enter number 1 or larger",
-        index_str
+        "Cannot parse {} value '{}', enter number from {} to {}",
+        option, value_str, min, max
     );
     process::exit(1);
 }
 
+fn parse_languages(languages: &str) -> Vec<String> {
+    if languages.trim().eq_ignore_ascii_case("all") {
+        return Vec::new();
+    }
+    languages
+        .split(',')
+        .map(|l| l.trim().to_owned())
+        .filter(|l| !l.is_empty())
+        .collect()
+}
+
+fn parse_match_types(types: &str) -> Vec<MatchType> {
+    let mut res: Vec<MatchType> = Vec::new();
+    for v in types.split(',') {
+        match MatchType::new(v) {
+            Ok(match_type) => res.push(match_type),
+            Err(err) => {
+                error!("{}", err);
+                process::exit(1);
+            }
+        }
+    }
+    res
+}
+
 fn parse_sources(sources: &str) -> Vec<i64> {
     let mut res: Vec<i64> = Vec::new();
     for v in sources.split(',') {
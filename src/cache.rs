@@ -0,0 +1,202 @@
+use super::Verified;
+use anyhow::{Context, Result};
+use log::warn;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// A persistent cache of verification results. Every result is kept in its
+/// own file in the cache directory. Results older than ttl are ignored and
+/// replaced by new ones.
+#[derive(Debug)]
+pub struct Cache {
+    dir: PathBuf,
+    ttl: Duration,
+    hits: AtomicUsize,
+    misses: AtomicUsize,
+    stored: AtomicUsize,
+    tmp_num: AtomicUsize,
+}
+
+/// Counts of cache lookups and writes.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CacheStats {
+    /// The number of results found in the cache.
+    pub hits: usize,
+    /// The number of results not found in the cache, or expired.
+    pub misses: usize,
+    /// The number of results saved to the cache.
+    pub stored: usize,
+}
+
+impl fmt::Display for CacheStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses, {} stored",
+            self.hits, self.misses, self.stored
+        )
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct Entry {
+    key: String,
+    created: u64,
+    result: Verified,
+}
+
+impl Cache {
+    /// Creates a cache in a directory. The directory is created if it does
+    /// not exist.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use gnverify::cache::Cache;
+    /// use std::time::Duration;
+    ///
+    /// let dir = std::env::temp_dir().join("gnverify-cache-doc");
+    /// let cache = Cache::new(&dir, Duration::from_secs(3600)).unwrap();
+    /// assert!(cache.get("Homo sapiens").is_none());
+    /// assert_eq!(cache.stats().misses, 1);
+    /// ```
+    pub fn new<P: AsRef<Path>>(dir: P, ttl: Duration) -> Result<Self> {
+        let dir = dir.as_ref().to_path_buf();
+        fs::create_dir_all(&dir)
+            .with_context(|| format!("cannot create cache directory {}", dir.display()))?;
+        Ok(Cache {
+            dir,
+            ttl,
+            hits: AtomicUsize::new(0),
+            misses: AtomicUsize::new(0),
+            stored: AtomicUsize::new(0),
+            tmp_num: AtomicUsize::new(0),
+        })
+    }
+
+    /// Returns a result saved under the key, if it exists and is not
+    /// expired.
+    pub fn get(&self, key: &str) -> Option<Verified> {
+        let res = self.read(key);
+        if res.is_some() {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.misses.fetch_add(1, Ordering::Relaxed);
+        }
+        res
+    }
+
+    /// Saves a result under the key. Errors are logged, but otherwise
+    /// ignored, because the cache is not essential for verification.
+    pub fn set(&self, key: &str, result: &Verified) {
+        match self.write(key, result) {
+            Ok(_) => {
+                self.stored.fetch_add(1, Ordering::Relaxed);
+            }
+            Err(err) => warn!("Cannot save result to cache: {:#}", err),
+        }
+    }
+
+    /// Returns counts of cache lookups and writes so far.
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            stored: self.stored.load(Ordering::Relaxed),
+        }
+    }
+
+    fn read(&self, key: &str) -> Option<Verified> {
+        let f = File::open(self.path(key)).ok()?;
+        let entry: Entry = serde_json::from_reader(BufReader::new(f)).ok()?;
+        if entry.key != key || now().saturating_sub(entry.created) > self.ttl.as_secs() {
+            return None;
+        }
+        Some(entry.result)
+    }
+
+    fn write(&self, key: &str, result: &Verified) -> Result<()> {
+        let path = self.path(key);
+        let dir = path.parent().context("cannot find cache subdirectory")?;
+        fs::create_dir_all(dir)?;
+        // Writes go to a temporary file first, so concurrent readers never
+        // see a partially written entry.
+        let tmp = path.with_extension(format!(
+            "{}-{}.tmp",
+            process::id(),
+            self.tmp_num.fetch_add(1, Ordering::Relaxed)
+        ));
+        let entry = Entry {
+            key: key.to_owned(),
+            created: now(),
+            result: result.clone(),
+        };
+        serde_json::to_writer(BufWriter::new(File::create(&tmp)?), &entry)?;
+        fs::rename(&tmp, &path)?;
+        Ok(())
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        let hash = format!("{:016x}", fnv1a(key.as_bytes()));
+        self.dir.join(&hash[0..2]).join(format!("{}.json", hash))
+    }
+}
+
+/// Returns default cache directory of gnverify in the user's cache
+/// directory, if it can be found.
+pub fn default_dir() -> Option<PathBuf> {
+    std::env::var_os("XDG_CACHE_HOME")
+        .filter(|d| !d.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|h| PathBuf::from(h).join(".cache")))
+        .map(|d| d.join("gnverify"))
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// FNV-1a hash. Unlike the hasher of std it is guaranteed to stay the same
+/// between Rust releases, so file names of the cache stay valid.
+fn fnv1a(data: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for b in data {
+        hash ^= u64::from(*b);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    hash
+}
+
+#[test]
+fn cache_roundtrip() {
+    let dir = std::env::temp_dir().join(format!("gnverify-cache-test-{}", process::id()));
+    let cache = Cache::new(&dir, Duration::from_secs(3600)).unwrap();
+    let result = Verified {
+        supplied_input: Some("Homo sapiens".to_owned()),
+        ..crate::verif::mock::no_match()
+    };
+    assert!(cache.get("Homo sapiens\t").is_none());
+    cache.set("Homo sapiens\t", &result);
+    let cached = cache.get("Homo sapiens\t").unwrap();
+    assert_eq!(cached.supplied_input, Some("Homo sapiens".to_owned()));
+    assert!(cache.get("Homo sapiens\t1").is_none());
+
+    fs::remove_dir_all(&dir).unwrap();
+    assert_eq!(
+        cache.stats(),
+        CacheStats {
+            hits: 1,
+            misses: 2,
+            stored: 1
+        }
+    );
+}
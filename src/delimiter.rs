@@ -0,0 +1,106 @@
+use super::GNVerifyError;
+use strum_macros::Display;
+
+/// The number of lines used to detect a delimiter.
+const SAMPLE_LINES: usize = 10;
+
+/// Indicates a delimiter of fields in the input.
+#[derive(Debug, Display, Clone, Default)]
+pub enum Delimiter {
+    /// Fields are separated by tabs.
+    #[default]
+    Tab,
+    /// Fields are separated by commas.
+    Comma,
+    /// Fields are separated by semicolons.
+    Semicolon,
+    /// The delimiter is detected from the first lines of the input.
+    Auto,
+}
+
+impl Delimiter {
+    /// Creates a new delimiter entity out of a string.
+    pub fn new(d: &str) -> Result<Self, GNVerifyError> {
+        match d {
+            "tab" | "\t" | "\\t" => Ok(Delimiter::Tab),
+            "comma" | "," => Ok(Delimiter::Comma),
+            "semicolon" | ";" => Ok(Delimiter::Semicolon),
+            "auto" => Ok(Delimiter::Auto),
+            _ => Err(GNVerifyError::InvalidDelimiterString {
+                delimiter: d.to_owned(),
+            }),
+        }
+    }
+
+    /// Returns the delimiter as a byte. For Auto the delimiter is detected
+    /// from a sample of the input data.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use gnverify::Delimiter;
+    ///
+    /// assert_eq!(Delimiter::Comma.to_byte(b""), b',');
+    /// let sample = b"id;name\n1;Homo sapiens\n2;Bubo bubo\n";
+    /// assert_eq!(Delimiter::Auto.to_byte(sample), b';');
+    /// ```
+    pub fn to_byte(&self, sample: &[u8]) -> u8 {
+        match self {
+            Delimiter::Tab => b'\t',
+            Delimiter::Comma => b',',
+            Delimiter::Semicolon => b';',
+            Delimiter::Auto => detect(sample),
+        }
+    }
+}
+
+/// Finds a delimiter that splits every line of the sample into the same
+/// number of fields. Tab wins if several delimiters fit, or if none does.
+fn detect(sample: &[u8]) -> u8 {
+    let mut lines: Vec<&[u8]> = sample.split(|b| *b == b'\n').collect();
+    // the last line of the sample might be cut in the middle
+    if lines.len() > 1 && !sample.ends_with(b"\n") {
+        lines.pop();
+    }
+    let lines: Vec<&[u8]> = lines
+        .into_iter()
+        .map(|l| l.strip_suffix(b"\r").unwrap_or(l))
+        .filter(|l| !l.is_empty())
+        .take(SAMPLE_LINES)
+        .collect();
+    for candidate in [b'\t', b';', b','].iter() {
+        let counts: Vec<usize> = lines.iter().map(|l| count(l, *candidate)).collect();
+        if let Some(first) = counts.first() {
+            if *first > 0 && counts.iter().all(|c| c == first) {
+                return *candidate;
+            }
+        }
+    }
+    b'\t'
+}
+
+/// Counts delimiters that are not inside quotes.
+fn count(line: &[u8], delimiter: u8) -> usize {
+    let mut quoted = false;
+    let mut res = 0;
+    for b in line {
+        if *b == b'"' {
+            quoted = !quoted;
+        } else if *b == delimiter && !quoted {
+            res += 1;
+        }
+    }
+    res
+}
+
+#[test]
+fn detect_delimiter() {
+    assert_eq!(detect(b"Homo sapiens\nBubo bubo\n"), b'\t');
+    assert_eq!(detect(b"1\tHomo sapiens\n2\tBubo bubo\n"), b'\t');
+    assert_eq!(
+        detect(b"1,\"Homo sapiens L., 1758\"\r\n2,Bubo bubo\r\n"),
+        b','
+    );
+    assert_eq!(detect(b"Homo sapiens L., 1758\nBubo bubo\n"), b'\t');
+    assert_eq!(detect(b"1;Homo sapiens\n2;Bubo bubo\n3;Bubo;"), b';');
+}
@@ -0,0 +1,272 @@
+use super::{Fields, GNVerify, Input};
+use anyhow::{bail, Context, Result};
+use crossbeam_channel::Sender;
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use zip::ZipArchive;
+
+/// Darwin Core term for scientific names.
+const SCIENTIFIC_NAME: &str = "http://rs.tdwg.org/dwc/terms/scientificName";
+
+/// Describes the core data file of a Darwin Core Archive according to
+/// its meta.xml.
+#[derive(Debug, Clone, Default)]
+pub struct Core {
+    /// Location of the core file in the archive.
+    pub location: String,
+    /// Type of core records, for example Taxon or Occurrence.
+    pub row_type: String,
+    /// Delimiter of fields.
+    pub delimiter: u8,
+    /// Character that encloses fields (if any).
+    pub quote: Option<u8>,
+    /// The number of header lines to skip.
+    pub ignore_header_lines: usize,
+    /// Position of the core ID field (the first field is 0).
+    pub id_index: Option<usize>,
+    /// Position of the scientificName field (the first field is 0).
+    pub name_index: usize,
+    /// Positions and short names of the core terms.
+    pub terms: Vec<(usize, String)>,
+}
+
+/// A Darwin Core Archive. It can be a zip file or a directory with
+/// meta.xml and data files.
+#[derive(Debug)]
+pub struct Archive {
+    path: PathBuf,
+    is_dir: bool,
+    core: Core,
+}
+
+impl Archive {
+    /// Opens a Darwin Core Archive and reads the description of its core
+    /// from meta.xml.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use gnverify::dwca::Archive;
+    ///
+    /// let archive = Archive::open("testdata/dwca").unwrap();
+    /// assert_eq!(archive.core().location, "taxon.txt");
+    /// assert_eq!(archive.core().name_index, 1);
+    /// assert_eq!(archive.core().id_index, Some(0));
+    /// ```
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let is_dir = path.is_dir();
+        let meta = if is_dir {
+            fs::read_to_string(path.join("meta.xml"))
+                .with_context(|| format!("cannot read meta.xml in {}", path.display()))?
+        } else {
+            let mut zip = ZipArchive::new(File::open(&path)?)
+                .with_context(|| format!("cannot open archive {}", path.display()))?;
+            let mut meta = String::new();
+            zip.by_name("meta.xml")
+                .context("archive does not contain meta.xml")?
+                .read_to_string(&mut meta)?;
+            meta
+        };
+        let core = parse_meta(&meta)?;
+        Ok(Archive { path, is_dir, core })
+    }
+
+    /// Returns the description of the core data file.
+    pub fn core(&self) -> &Core {
+        &self.core
+    }
+
+    /// Reads core records, and sends their scientific names to the input
+    /// channel in batches of batch_size. Core IDs are used as IDs of the
+    /// inputs. If with_fields of GNVerify is set, all core fields are kept
+    /// under names of their terms.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use gnverify::{dwca::Archive, GNVerify};
+    /// use crossbeam_channel::unbounded;
+    ///
+    /// let archive = Archive::open("testdata/dwca").unwrap();
+    /// let (in_s, in_r) = unbounded();
+    /// archive.send_inputs(&GNVerify::new(), in_s).unwrap();
+    /// let inputs = in_r.recv().unwrap();
+    /// assert_eq!(inputs.len(), 4);
+    /// assert_eq!(inputs[0].id, Some("t-1".to_owned()));
+    /// assert_eq!(inputs[0].name, "Homo sapiens");
+    /// ```
+    pub fn send_inputs(&self, gnv: &GNVerify, in_s: Sender<Vec<Input>>) -> Result<()> {
+        if self.is_dir {
+            let f = File::open(self.path.join(&self.core.location))
+                .with_context(|| format!("cannot open {}", self.core.location))?;
+            self.send_records(f, gnv, in_s)
+        } else {
+            let mut zip = ZipArchive::new(File::open(&self.path)?)?;
+            let f = zip
+                .by_name(&self.core.location)
+                .with_context(|| format!("archive does not contain {}", self.core.location))?;
+            self.send_records(f, gnv, in_s)
+        }
+    }
+
+    fn send_records<R: Read>(&self, r: R, gnv: &GNVerify, in_s: Sender<Vec<Input>>) -> Result<()> {
+        let core = &self.core;
+        let mut builder = csv::ReaderBuilder::new();
+        builder
+            .delimiter(core.delimiter)
+            .has_headers(false)
+            .flexible(true);
+        match core.quote {
+            Some(q) => builder.quote(q),
+            None => builder.quoting(false),
+        };
+        let rdr = builder.from_reader(r);
+
+        let batch_size = gnv.batch_size.max(1);
+        let mut inputs: Vec<Input> = Vec::with_capacity(batch_size);
+        for result in rdr.into_records().skip(core.ignore_header_lines) {
+            let record = result?;
+            let name = match record.get(core.name_index) {
+                Some(name) => name.to_owned(),
+                None => continue,
+            };
+            let id = core
+                .id_index
+                .and_then(|i| record.get(i))
+                .map(|id| id.to_owned());
+            let mut fields = None;
+            if gnv.with_fields {
+                fields = Some(Fields(
+                    core.terms
+                        .iter()
+                        .map(|(i, t)| (t.to_owned(), record.get(*i).unwrap_or("").to_owned()))
+                        .collect(),
+                ));
+            }
+            inputs.push(Input { id, name, fields });
+            if inputs.len() == batch_size {
+                in_s.send(inputs)
+                    .context("cannot send names for verification")?;
+                inputs = Vec::with_capacity(batch_size);
+            }
+        }
+        in_s.send(inputs)
+            .context("cannot send names for verification")?;
+        Ok(())
+    }
+}
+
+/// Returns true if the path looks like a Darwin Core Archive: a zip file,
+/// or a directory with meta.xml.
+pub fn is_archive<P: AsRef<Path>>(path: P) -> bool {
+    let path = path.as_ref();
+    if path.is_dir() {
+        return path.join("meta.xml").is_file();
+    }
+    let mut magic = [0u8; 4];
+    match File::open(path).and_then(|mut f| f.read_exact(&mut magic)) {
+        Ok(_) => &magic == b"PK\x03\x04",
+        Err(_) => false,
+    }
+}
+
+/// Parses description of the core from meta.xml. Missing attributes get
+/// default values of the Darwin Core text guide: fields are terminated by
+/// comma and enclosed by double quotes.
+fn parse_meta(xml: &str) -> Result<Core> {
+    let doc = roxmltree::Document::parse(xml).context("cannot parse meta.xml")?;
+    let node = doc
+        .descendants()
+        .find(|n| n.has_tag_name("core"))
+        .context("meta.xml does not describe core")?;
+    let location = node
+        .descendants()
+        .find(|n| n.has_tag_name("location"))
+        .and_then(|n| n.text())
+        .context("meta.xml does not have location of core")?
+        .trim()
+        .to_owned();
+    let mut core = Core {
+        location,
+        row_type: node.attribute("rowType").unwrap_or("").to_owned(),
+        delimiter: parse_delimiter(node.attribute("fieldsTerminatedBy").unwrap_or(","))?,
+        // An empty fieldsEnclosedBy turns quoting off, a missing one means
+        // the default double quote.
+        quote: match node.attribute("fieldsEnclosedBy") {
+            Some(q) => q.bytes().next(),
+            None => Some(b'"'),
+        },
+        ignore_header_lines: node
+            .attribute("ignoreHeaderLines")
+            .unwrap_or("0")
+            .parse()
+            .context("cannot parse ignoreHeaderLines")?,
+        ..Default::default()
+    };
+    let mut name_index = None;
+    for n in node.children().filter(|n| n.is_element()) {
+        let index = match n.attribute("index") {
+            Some(i) => i.parse::<usize>().context("cannot parse field index")?,
+            None => continue,
+        };
+        if n.has_tag_name("id") || n.has_tag_name("coreid") {
+            core.id_index = Some(index);
+            core.terms.push((index, "id".to_owned()));
+        } else if n.has_tag_name("field") {
+            let term = n.attribute("term").unwrap_or("");
+            if term == SCIENTIFIC_NAME {
+                name_index = Some(index);
+            }
+            core.terms.push((index, short_term(term)));
+        }
+    }
+    core.name_index = match name_index {
+        Some(i) => i,
+        None => bail!(
+            "core of the archive does not have {} field",
+            SCIENTIFIC_NAME
+        ),
+    };
+    Ok(core)
+}
+
+fn parse_delimiter(d: &str) -> Result<u8> {
+    match d {
+        "\\t" | "\t" => Ok(b'\t'),
+        d if d.len() == 1 => Ok(d.as_bytes()[0]),
+        _ => bail!("cannot use {:?} as a fields delimiter", d),
+    }
+}
+
+fn short_term(term: &str) -> String {
+    term.rsplit(['/', '#']).next().unwrap_or(term).to_owned()
+}
+
+#[test]
+fn zipped_archive() {
+    use crossbeam_channel::unbounded;
+
+    let archive = Archive::open("testdata/dwca.zip").unwrap();
+    assert_eq!(archive.core().location, "taxa.csv");
+    assert_eq!(archive.core().delimiter, b',');
+    assert_eq!(archive.core().quote, Some(b'"'));
+    assert!(is_archive("testdata/dwca.zip"));
+
+    let (in_s, in_r) = unbounded();
+    archive.send_inputs(&GNVerify::new(), in_s).unwrap();
+    let inputs = in_r.recv().unwrap();
+    assert_eq!(inputs.len(), 3);
+    assert_eq!(inputs[1].id, Some("t-2".to_owned()));
+    assert_eq!(inputs[1].name, "Felis concolor Linnaeus, 1771");
+    assert_eq!(inputs[2].name, "Bubo bubbo");
+
+    let explicit = r#"<archive><core fieldsTerminatedBy="\t" fieldsEnclosedBy="">
+        <files><location>taxon.txt</location></files>
+        <field index="0" term="http://rs.tdwg.org/dwc/terms/scientificName"/>
+        </core></archive>"#;
+    let core = parse_meta(explicit).unwrap();
+    assert_eq!(core.delimiter, b'\t');
+    assert_eq!(core.quote, None);
+}
@@ -11,4 +11,57 @@ pub enum GNVerifyError {
         /// Contains the string supplied format creation.
         format: String,
     },
+    /// Indicates that a user entered a string that cannot be
+    /// converted to a Delimiter type.
+    #[error("cannot convert {delimiter:?} to an input delimiter value")]
+    InvalidDelimiterString {
+        /// Contains the string supplied for delimiter creation.
+        delimiter: String,
+    },
+    /// Indicates that a user entered a string that cannot be
+    /// converted to a MatchType.
+    #[error("cannot convert {match_type:?} to a match type value")]
+    InvalidMatchTypeString {
+        /// Contains the string supplied for match type creation.
+        match_type: String,
+    },
+    /// Indicates that a field position is not a positive number.
+    #[error("cannot use {field:?} as a field position, enter number 1 or larger")]
+    InvalidFieldIndex {
+        /// Contains the supplied field position.
+        field: String,
+    },
+    /// Indicates that a field with a given name is not in the header of the
+    /// input.
+    #[error("cannot find field {field:?}, available headers: {headers}")]
+    UnknownField {
+        /// Contains the supplied field name.
+        field: String,
+        /// Contains comma-separated names of available headers.
+        headers: String,
+    },
+    /// Indicates that a connection to gnindex was not made in time.
+    #[error("timeout: cannot connect to {url}{}", in_secs(.timeout))]
+    ConnectTimeout {
+        /// Contains URL of the request.
+        url: String,
+        /// Contains the connect timeout in seconds, if it is set.
+        timeout: Option<u64>,
+    },
+    /// Indicates that a request to gnindex did not finish in time.
+    #[error("timeout: no response from {url}{}", in_secs(.timeout))]
+    Timeout {
+        /// Contains URL of the request.
+        url: String,
+        /// Contains the request timeout in seconds, if it is set.
+        timeout: Option<u64>,
+    },
+}
+
+/// Formats a timeout for error messages, an unset timeout is omitted.
+fn in_secs(timeout: &Option<u64>) -> String {
+    match timeout {
+        Some(secs) => format!(" in {}s", secs),
+        None => String::new(),
+    }
 }
@@ -2,28 +2,35 @@ use super::GNVerifyError;
 use strum_macros::Display;
 
 /// Indicates desired format for the output;
-#[derive(Debug, Display, Clone)]
+#[derive(Debug, Display, Clone, Default)]
 pub enum Format {
     /// Outputs verification results in CSV format.
+    #[default]
     CSV,
-    /// Outputs verification results in as a compact JSON format.
+    /// Outputs verification results in tab-separated format.
+    TSV,
+    /// Outputs verification results in tab-separated format with fields
+    /// named by Darwin Core terms.
+    DwC,
+    /// Outputs verification results in as a compact JSON format, one JSON
+    /// document per line (JSON Lines).
     Compact,
+    /// Outputs verification results as one JSON array.
+    JSON,
     /// Outputs verification results in a prettyfied JSON format.
     Pretty,
 }
 
-impl Default for Format {
-    fn default() -> Self {
-        Format::CSV
-    }
-}
 impl Format {
     /// Creates a new format entity out of a string.
     pub fn new(f: &str) -> Result<Self, GNVerifyError> {
         match f {
             "pretty" => Ok(Format::Pretty),
-            "compact" => Ok(Format::Compact),
+            "compact" | "jsonl" => Ok(Format::Compact),
+            "json" => Ok(Format::JSON),
             "csv" => Ok(Format::CSV),
+            "tsv" => Ok(Format::TSV),
+            "dwc" => Ok(Format::DwC),
             _ => Err(GNVerifyError::InvalidFormatString {
                 format: f.to_owned(),
             }),
@@ -35,3 +42,12 @@ impl Format {
 fn format_as_str() {
     assert_eq!(Format::CSV.to_string(), "CSV")
 }
+
+#[test]
+fn format_from_str() {
+    assert_eq!(Format::new("tsv").unwrap().to_string(), "TSV");
+    assert_eq!(Format::new("jsonl").unwrap().to_string(), "Compact");
+    assert_eq!(Format::new("json").unwrap().to_string(), "JSON");
+    assert_eq!(Format::new("dwc").unwrap().to_string(), "DwC");
+    assert!(Format::new("xml").is_err());
+}
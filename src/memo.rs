@@ -0,0 +1,185 @@
+use super::Verified;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Condvar, Mutex};
+
+/// The number of recent results kept by the memo by default.
+pub(crate) const MEMO_CAPACITY: usize = 10_000;
+
+/// Verification result of a unique name-string together with the number of
+/// retries it took and the time spent waiting between them. The error is
+/// kept as a string, so it can be shared by all inputs with the same
+/// name-string.
+#[derive(Debug, Clone)]
+pub(crate) struct Resolved<T = Verified, E = String> {
+    pub result: Result<T, E>,
+    pub retries: i64,
+    pub wait_ms: u64,
+}
+
+/// Keeps results of name-strings verified during one run, so a unique
+/// name-string is sent for verification only once, even if it is repeated
+/// in batches processed by different workers. Only capacity of the most
+/// recent results are kept, older ones are dropped and have to be found
+/// again, for example in the cache. This way memory does not grow with the
+/// size of the input.
+#[derive(Debug)]
+pub(crate) struct Memo {
+    state: Mutex<State>,
+    ready: Condvar,
+    capacity: usize,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    /// Results by name-string. None means that the name-string is claimed
+    /// by a worker and its result is not ready yet.
+    results: HashMap<String, Option<Resolved>>,
+    /// Name-strings with ready results, from the oldest to the newest.
+    finished: VecDeque<String>,
+}
+
+/// Name-strings sorted by their state in the memo.
+#[derive(Debug, Default)]
+pub(crate) struct Claim {
+    /// Name-strings with ready results.
+    pub known: HashMap<String, Resolved>,
+    /// Name-strings that the caller has to verify and then pass to finish.
+    pub claimed: Vec<String>,
+    /// Name-strings that are being verified by other workers.
+    pub pending: Vec<String>,
+}
+
+impl Default for Memo {
+    fn default() -> Self {
+        Memo::new(MEMO_CAPACITY)
+    }
+}
+
+impl Memo {
+    /// Creates a memo that keeps up to capacity of ready results.
+    pub fn new(capacity: usize) -> Self {
+        Memo {
+            state: Mutex::new(State::default()),
+            ready: Condvar::new(),
+            capacity,
+        }
+    }
+
+    /// Sorts unique name-strings into known, claimed, and pending. Unknown
+    /// name-strings become claimed by the caller.
+    pub fn claim(&self, names: Vec<String>) -> Claim {
+        let mut state = self.state.lock().unwrap();
+        let mut claim = Claim::default();
+        for name in names {
+            match state.results.get(&name) {
+                Some(Some(resolved)) => {
+                    claim.known.insert(name, resolved.clone());
+                }
+                Some(None) => claim.pending.push(name),
+                None => {
+                    state.results.insert(name.clone(), None);
+                    claim.claimed.push(name);
+                }
+            }
+        }
+        claim
+    }
+
+    /// Saves results of claimed name-strings, drops the oldest results over
+    /// capacity, and wakes up workers that wait for results.
+    pub fn finish(&self, resolved: &HashMap<String, Resolved>) {
+        let mut state = self.state.lock().unwrap();
+        for (name, res) in resolved {
+            state.results.insert(name.clone(), Some(res.clone()));
+            state.finished.push_back(name.clone());
+        }
+        while state.finished.len() > self.capacity {
+            if let Some(name) = state.finished.pop_front() {
+                state.results.remove(&name);
+            }
+        }
+        self.ready.notify_all();
+    }
+
+    /// Waits until results of name-strings claimed by other workers are
+    /// ready and returns them. Name-strings whose results were dropped
+    /// before the caller got them are returned separately, they have to be
+    /// claimed again.
+    pub fn wait(&self, names: Vec<String>) -> (HashMap<String, Resolved>, Vec<String>) {
+        let mut res = HashMap::with_capacity(names.len());
+        let mut dropped = Vec::new();
+        let mut state = self.state.lock().unwrap();
+        for name in names {
+            loop {
+                match state.results.get(&name) {
+                    Some(Some(resolved)) => {
+                        res.insert(name, resolved.clone());
+                        break;
+                    }
+                    Some(None) => state = self.ready.wait(state).unwrap(),
+                    None => {
+                        dropped.push(name);
+                        break;
+                    }
+                }
+            }
+        }
+        (res, dropped)
+    }
+}
+
+#[test]
+fn memo_claim() {
+    let memo = Memo::default();
+    let claim = memo.claim(vec!["Bubo bubo".to_owned()]);
+    assert_eq!(claim.claimed, vec!["Bubo bubo".to_owned()]);
+    let claim = memo.claim(vec!["Bubo bubo".to_owned()]);
+    assert_eq!(claim.pending, vec!["Bubo bubo".to_owned()]);
+
+    let mut resolved = HashMap::new();
+    resolved.insert(
+        "Bubo bubo".to_owned(),
+        Resolved {
+            result: Err("timeout".to_owned()),
+            retries: 3,
+            wait_ms: 3500,
+        },
+    );
+    memo.finish(&resolved);
+    let (found, dropped) = memo.wait(vec!["Bubo bubo".to_owned()]);
+    assert_eq!(found["Bubo bubo"].retries, 3);
+    assert!(dropped.is_empty());
+    let claim = memo.claim(vec!["Bubo bubo".to_owned()]);
+    assert_eq!(claim.known.len(), 1);
+}
+
+#[test]
+fn memo_capacity() {
+    let memo = Memo::new(10);
+    for batch in 0..100 {
+        let names: Vec<String> = (0..5).map(|i| format!("Name {} {}", batch, i)).collect();
+        let claim = memo.claim(names);
+        let resolved = claim
+            .claimed
+            .into_iter()
+            .map(|name| {
+                let res = Resolved {
+                    result: Err("no match".to_owned()),
+                    retries: 0,
+                    wait_ms: 0,
+                };
+                (name, res)
+            })
+            .collect();
+        memo.finish(&resolved);
+        let state = memo.state.lock().unwrap();
+        assert!(state.results.len() <= 10);
+        assert_eq!(state.results.len(), state.finished.len());
+    }
+    let claim = memo.claim(vec!["Name 0 0".to_owned(), "Name 99 4".to_owned()]);
+    assert_eq!(claim.claimed, vec!["Name 0 0".to_owned()]);
+    assert_eq!(claim.known.len(), 1);
+    let (found, dropped) = memo.wait(vec!["Name 1 1".to_owned()]);
+    assert!(found.is_empty());
+    assert_eq!(dropped, vec!["Name 1 1".to_owned()]);
+}
@@ -0,0 +1,80 @@
+use super::GNVerifyError;
+
+/// Locates a field in the input either by its position or by its header.
+#[derive(Debug, Clone, PartialEq)]
+pub enum NameField {
+    /// Position of the field, the first field is 1.
+    Index(usize),
+    /// Name of the field in the header of the input.
+    Header(String),
+}
+
+impl Default for NameField {
+    fn default() -> Self {
+        NameField::Index(1)
+    }
+}
+
+impl NameField {
+    /// Creates a new field locator out of a string. Numbers are treated as
+    /// positions of the field, other strings as header names.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use gnverify::NameField;
+    ///
+    /// assert_eq!(NameField::new("2").unwrap(), NameField::Index(2));
+    /// assert_eq!(
+    ///     NameField::new("scientificName").unwrap(),
+    ///     NameField::Header("scientificName".to_owned())
+    /// );
+    /// assert!(NameField::new("0").is_err());
+    /// ```
+    pub fn new(f: &str) -> Result<Self, GNVerifyError> {
+        let f = f.trim();
+        match f.parse::<i64>() {
+            Ok(i) if i > 0 => Ok(NameField::Index(i as usize)),
+            Ok(_) => Err(GNVerifyError::InvalidFieldIndex {
+                field: f.to_owned(),
+            }),
+            Err(_) => Ok(NameField::Header(f.to_owned())),
+        }
+    }
+
+    /// Returns the position of the field in a record, starting from 0.
+    /// Headers are required to find a field by its name. Index 0 is not a
+    /// valid position, because positions start from 1.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use gnverify::NameField;
+    ///
+    /// let headers = vec!["id".to_owned(), "scientificName".to_owned()];
+    /// let field = NameField::Header("scientificName".to_owned());
+    /// assert_eq!(field.position(&headers).unwrap(), 1);
+    /// assert_eq!(NameField::Index(1).position(&headers).unwrap(), 0);
+    /// assert!(NameField::Header("name".to_owned()).position(&headers).is_err());
+    /// assert!(NameField::Index(0).position(&headers).is_err());
+    /// ```
+    pub fn position(&self, headers: &[String]) -> Result<usize, GNVerifyError> {
+        match self {
+            NameField::Index(i) => {
+                i.checked_sub(1)
+                    .ok_or_else(|| GNVerifyError::InvalidFieldIndex {
+                        field: i.to_string(),
+                    })
+            }
+            NameField::Header(name) => {
+                headers
+                    .iter()
+                    .position(|h| h == name)
+                    .ok_or_else(|| GNVerifyError::UnknownField {
+                        field: name.to_owned(),
+                        headers: headers.join(", "),
+                    })
+            }
+        }
+    }
+}
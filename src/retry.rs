@@ -0,0 +1,176 @@
+use super::GNVerifyError;
+use std::collections::hash_map::RandomState;
+use std::error::Error;
+use std::hash::{BuildHasher, Hasher};
+use std::io;
+use std::time::Duration;
+
+/// Determines how many times and how long after a failure a batch of
+/// name-strings is sent for verification again. Only transient errors
+/// (timeouts, connection failures, 5xx and 429 responses) are retried.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// The maximum number of retries after the first attempt.
+    pub max_retries: u32,
+    /// Delay before the first retry.
+    pub initial_delay: Duration,
+    /// Every next delay is the previous one multiplied by this factor.
+    pub backoff_factor: f64,
+    /// Maximum random deviation of a delay as a fraction of it, from 0 to
+    /// 1. It prevents many workers from retrying at the same moment.
+    pub jitter: f64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_retries: 3,
+            initial_delay: Duration::from_millis(500),
+            backoff_factor: 2.0,
+            jitter: 0.2,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Returns the delay before a retry, the first retry is 1. The delay
+    /// grows exponentially with every retry and is randomly changed by up
+    /// to jitter fraction of it.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use gnverify::retry::RetryPolicy;
+    /// use std::time::Duration;
+    ///
+    /// let policy = RetryPolicy {
+    ///     initial_delay: Duration::from_millis(100),
+    ///     jitter: 0.0,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(policy.delay(1), Duration::from_millis(100));
+    /// assert_eq!(policy.delay(3), Duration::from_millis(400));
+    /// ```
+    pub fn delay(&self, retry: u32) -> Duration {
+        let exp = retry.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let mut secs = self.initial_delay.as_secs_f64() * self.backoff_factor.max(1.0).powi(exp);
+        let jitter = self.jitter.clamp(0.0, 1.0);
+        if jitter > 0.0 {
+            secs *= 1.0 + jitter * (2.0 * random_fraction() - 1.0);
+        }
+        Duration::from_secs_f64(secs.clamp(0.0, MAX_DELAY_SECS))
+    }
+}
+
+/// The longest delay between retries.
+const MAX_DELAY_SECS: f64 = 600.0;
+
+/// Returns true if an error can disappear if the request is repeated:
+/// timeouts, failed or broken connections, 5xx and 429 responses. Errors
+/// of building a request, for example a bad URL, are not transient.
+pub fn is_transient(err: &anyhow::Error) -> bool {
+    if let Some(GNVerifyError::Timeout { .. } | GNVerifyError::ConnectTimeout { .. }) =
+        err.downcast_ref::<GNVerifyError>()
+    {
+        return true;
+    }
+    if let Some(err) = err.downcast_ref::<reqwest::Error>() {
+        let too_many_requests = err.status().is_some_and(|s| s.as_u16() == 429);
+        return err.is_timeout()
+            || err.is_server_error()
+            || too_many_requests
+            || is_connection_failure(err);
+    }
+    match err.downcast_ref::<io::Error>() {
+        Some(err) => err.kind() == io::ErrorKind::TimedOut,
+        None => false,
+    }
+}
+
+/// Returns true if a connection to the server could not be made or was
+/// broken before the response was received.
+fn is_connection_failure(err: &reqwest::Error) -> bool {
+    let source = match err.get_ref() {
+        Some(source) => source,
+        None => return false,
+    };
+    if let Some(err) = source.downcast_ref::<hyper::Error>() {
+        let io_source = err.source().is_some_and(|s| s.is::<io::Error>());
+        return err.is_connect()
+            || err.is_incomplete_message()
+            || err.is_closed()
+            || err.is_canceled()
+            || io_source;
+    }
+    match source.downcast_ref::<io::Error>() {
+        Some(err) => matches!(
+            err.kind(),
+            io::ErrorKind::ConnectionReset
+                | io::ErrorKind::ConnectionAborted
+                | io::ErrorKind::BrokenPipe
+                | io::ErrorKind::UnexpectedEof
+        ),
+        None => false,
+    }
+}
+
+/// Returns true if an error does not depend on data that were sent: the
+/// request could not be made, it timed out, or the server answered that the
+/// whole service is unavailable (502, 503, 504) or overloaded (429).
+pub fn is_service_error(err: &anyhow::Error) -> bool {
+    if let Some(GNVerifyError::Timeout { .. } | GNVerifyError::ConnectTimeout { .. }) =
+        err.downcast_ref::<GNVerifyError>()
+    {
+        return true;
+    }
+    match err.downcast_ref::<reqwest::Error>() {
+        Some(err) => {
+            let unavailable = err
+                .status()
+                .is_some_and(|s| [429, 502, 503, 504].contains(&s.as_u16()));
+            unavailable || err.is_timeout() || err.is_http()
+        }
+        None => false,
+    }
+}
+
+/// Returns a random number from 0 to 1. Keys of RandomState are random,
+/// which is enough for jitter.
+fn random_fraction() -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u8(0);
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[test]
+fn delay_with_jitter() {
+    let policy = RetryPolicy {
+        initial_delay: Duration::from_secs(1),
+        jitter: 0.5,
+        ..Default::default()
+    };
+    for _ in 0..20 {
+        let delay = policy.delay(2);
+        assert!(delay >= Duration::from_secs(1) && delay <= Duration::from_secs(3));
+    }
+    assert!(!is_transient(&anyhow::anyhow!("gnindex remote error")));
+    let timeout = io::Error::new(io::ErrorKind::TimedOut, "timeout");
+    assert!(is_transient(&anyhow::Error::new(timeout)));
+    let not_found = io::Error::new(io::ErrorKind::NotFound, "not found");
+    assert!(!is_transient(&anyhow::Error::new(not_found)));
+}
+
+#[test]
+fn request_errors() {
+    let client = reqwest::Client::new();
+    let bad_header = client
+        .post("http://127.0.0.1:1/api")
+        .header("bad header", "value")
+        .send()
+        .unwrap_err();
+    assert!(bad_header.is_http());
+    assert!(!is_transient(&anyhow::Error::new(bad_header)));
+
+    let refused = client.post("http://127.0.0.1:1/api").send().unwrap_err();
+    assert!(is_transient(&anyhow::Error::new(refused)));
+}
@@ -0,0 +1,37 @@
+use super::{Input, Verified};
+use anyhow::Result;
+use std::fmt::Debug;
+
+/// A service that verifies name-strings. The default backend sends names to
+/// a remote gnindex server, other implementations can be used for testing or
+/// for working without network access.
+pub trait Backend: Debug + Send + Sync {
+    /// Takes a batch of inputs and returns verification results for each of
+    /// them in the same order. Options of the query determine which results
+    /// are returned.
+    fn verify(&self, inputs: &[Input], options: &QueryOptions) -> Result<Vec<Verified>>;
+
+    /// Identifies the source of results, for example URL of a server.
+    /// Cached results of different sources are kept apart. By default it is
+    /// the name of the backend type.
+    fn id(&self) -> String {
+        std::any::type_name::<Self>().to_owned()
+    }
+}
+
+/// Options of a verification query that change results returned by a
+/// backend.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct QueryOptions {
+    /// IDs of Data Sources. Matches from these Data Sources are returned in
+    /// preferred results.
+    pub sources: Option<Vec<i64>>,
+    /// IDs of Data Sources. If they are set, name-strings are matched only
+    /// to these Data Sources.
+    pub only_sources: Option<Vec<i64>>,
+    /// If true, all matches of a name-string are returned, not only the best
+    /// one.
+    pub all_matches: bool,
+    /// If true, vernacular names of matched taxa are returned.
+    pub vernaculars: bool,
+}
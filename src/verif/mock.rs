@@ -0,0 +1,123 @@
+use super::remote::resolver;
+use super::{Backend, Input, QueryOptions, Verified};
+use anyhow::{Context, Result};
+use graphql_client::Response;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// Offline backend that returns canned verification results. Canned results
+/// are loaded from JSON documents in the same format as responses of gnindex
+/// GraphQL API. Name-strings without canned results are returned as not
+/// matched.
+#[derive(Debug, Default, Clone)]
+pub struct Mock {
+    responses: HashMap<String, Verified>,
+}
+
+impl Mock {
+    /// Creates a new mock backend without any canned results.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new mock backend from a JSON response of gnindex.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use gnverify::Mock;
+    ///
+    /// let mock = Mock::from_json(include_str!("../../testdata/verified.json")).unwrap();
+    /// assert!(mock.len() > 0);
+    /// ```
+    pub fn from_json(json: &str) -> Result<Self> {
+        let response: Response<resolver::ResponseData> =
+            serde_json::from_str(json).context("cannot parse mock responses")?;
+        let data = response
+            .data
+            .context("mock responses do not contain data")?;
+        let mut mock = Mock::new();
+        for verified in data.name_resolver.responses {
+            mock.add(verified);
+        }
+        Ok(mock)
+    }
+
+    /// Creates a new mock backend from a file with a JSON response of gnindex.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let json = fs::read_to_string(path.as_ref())
+            .with_context(|| format!("cannot read {}", path.as_ref().display()))?;
+        Mock::from_json(&json)
+    }
+
+    /// Adds a canned result. It will be returned for a name-string that is
+    /// the same as the supplied input of the result.
+    pub fn add(&mut self, verified: Verified) {
+        let name = verified.supplied_input.clone().unwrap_or_default();
+        self.responses.insert(name, verified);
+    }
+
+    /// Returns the number of canned results.
+    pub fn len(&self) -> usize {
+        self.responses.len()
+    }
+
+    /// Returns true if there are no canned results.
+    pub fn is_empty(&self) -> bool {
+        self.responses.is_empty()
+    }
+}
+
+impl Backend for Mock {
+    fn verify(&self, inputs: &[Input], options: &QueryOptions) -> Result<Vec<Verified>> {
+        let mut res: Vec<Verified> = Vec::with_capacity(inputs.len());
+        for input in inputs {
+            let mut verified = match self.responses.get(&input.name) {
+                Some(verified) => verified.clone(),
+                None => no_match(),
+            };
+            verified.supplied_input = Some(input.name.to_owned());
+            verified.supplied_id = input.id.to_owned();
+            if let Some(srs) = &options.only_sources {
+                verified.results.retain(|r| srs.contains(&r.data_source.id));
+                verified
+                    .preferred_results
+                    .retain(|r| srs.contains(&r.data_source.id));
+            }
+            if !options.all_matches {
+                verified.results.truncate(1);
+            }
+            if !options.vernaculars {
+                verified
+                    .results
+                    .iter_mut()
+                    .for_each(|r| r.vernaculars.clear());
+                verified
+                    .preferred_results
+                    .iter_mut()
+                    .for_each(|r| r.vernaculars.clear());
+            }
+            verified
+                .preferred_results
+                .retain(|r| match &options.sources {
+                    Some(srs) => srs.contains(&r.data_source.id),
+                    None => false,
+                });
+            res.push(verified);
+        }
+        Ok(res)
+    }
+}
+
+pub(crate) fn no_match() -> Verified {
+    Verified {
+        total: 0,
+        supplied_id: None,
+        supplied_input: None,
+        quality_summary: None,
+        matched_data_sources: 0,
+        results: Vec::new(),
+        preferred_results: Vec::new(),
+    }
+}
@@ -1,18 +1,60 @@
+pub mod backend;
+pub mod mock;
 pub mod output;
 pub mod output_csv;
+pub mod output_dwc;
+pub mod proxy;
 pub mod remote;
 
-pub use output::{MatchType, Output};
+pub use backend::{Backend, QueryOptions};
+pub use mock::Mock;
+pub use output::MatchType;
 pub use output_csv::OutputCSV;
+pub use output_dwc::OutputDwC;
+pub use remote::{Remote, RemoteOptions};
+use serde::ser::{Serialize, SerializeMap, Serializer};
 pub type Verified = remote::resolver::ResolverNameResolverResponses;
 pub type VerifiedData = remote::resolver::ResolverNameResolverResponsesResults;
 pub type VerifiedPreferredData = remote::resolver::ResolverNameResolverResponsesPreferredResults;
 
 /// The input format to send to gnindex server.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct Input {
     /// Optional ID attached to a name-string.
     pub id: Option<String>,
     /// Name-string to verify.
     pub name: String,
+    /// Optional original fields of the input record. They are not sent for
+    /// verification, but are attached to the output.
+    pub fields: Option<Fields>,
+}
+
+/// Original fields of an input record as pairs of field names and values.
+/// It is serialized to JSON as an object.
+#[derive(Debug, Default, Clone)]
+pub struct Fields(pub Vec<(String, String)>);
+
+impl Fields {
+    /// Returns names of the fields.
+    pub fn names(&self) -> Vec<String> {
+        self.0.iter().map(|(k, _)| k.to_owned()).collect()
+    }
+
+    /// Returns values of the fields.
+    pub fn values(&self) -> Vec<String> {
+        self.0.iter().map(|(_, v)| v.to_owned()).collect()
+    }
+}
+
+impl Serialize for Fields {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (k, v) in &self.0 {
+            map.serialize_entry(k, v)?;
+        }
+        map.end()
+    }
 }
@@ -1,6 +1,9 @@
-use super::OutputCSV;
+use super::{Fields, OutputCSV, OutputDwC};
 use super::{Verified, VerifiedData, VerifiedPreferredData};
+use crate::acceptance::Acceptance;
+use crate::GNVerifyError;
 use serde::{Serialize, Serializer};
+use std::fmt;
 use strum_macros::Display;
 
 trait ToResultData {
@@ -12,6 +15,9 @@ trait ToResultData {
 #[derive(Serialize, Debug, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct Output {
+    /// ID supplied by user together with the name-string.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
     /// Name-string supplied by user for verification.
     pub name: String,
     /// Match type of the best result after verification attempt.
@@ -26,6 +32,12 @@ pub struct Output {
     /// How many retries were needed to send the name-string to gnindex
     /// server.
     pub retries: i64,
+    /// Time in milliseconds spent waiting between retries.
+    pub wait_ms: u64,
+    /// IDs of Data Sources the name-string was matched to, if matching was
+    /// limited to them.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub only_sources: Option<Vec<i64>>,
     /// Contains an error string (if any) after verification attempt.
     pub error: Option<String>,
     /// The apparent best match of the name-string to gnindex data sets.
@@ -35,9 +47,16 @@ pub struct Output {
     /// and gnindex data.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub best_result: Option<ResultData>,
+    /// All matches of the name-string, the best one goes first. They are
+    /// returned only if all matches were requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub all_results: Option<Vec<ResultData>>,
     /// Contains all matches found in the user-specified Data Sources.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub preferred_results: Option<Vec<ResultData>>,
+    /// Original fields of the input record (if they were kept).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<Fields>,
 }
 
 /// Matching result from a Data Source.
@@ -77,12 +96,66 @@ pub struct ResultData {
     /// Edit distance of stemmed version of the name-string. Stem version
     /// does not include suffixes of specific and infraspecific epithets.
     stem_edit_distance: i64,
+    /// Score of the match and its components.
+    score: Score,
+    /// The reason why the match was downgraded to NoMatch, if it did not
+    /// meet acceptance criteria.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reject_reason: Option<String>,
+    /// Vernacular names of the matched taxon (if they were requested).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vernaculars: Option<Vec<Vernacular>>,
+}
+
+/// A vernacular (common) name of a taxon.
+#[derive(Serialize, Debug, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Vernacular {
+    /// The vernacular name.
+    name: String,
+    /// Language of the name, as it is given by the Data Source.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+    /// Locality where the name is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    locality: Option<String>,
+    /// Code of the country where the name is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    country_code: Option<String>,
+}
+
+/// Score of a match that gnindex uses to sort matches of a name-string.
+/// The match with the highest score becomes the best result.
+#[derive(Serialize, Debug, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Score {
+    /// Overall score of the match.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<f64>,
+    /// Score of the match type, exact matches get higher scores than
+    /// partial or fuzzy ones.
+    match_type: i64,
+    /// Score of the type of the name (uninomial, binomial, trinomial).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name_type: Option<i64>,
+    /// Score of the match of authorships.
+    author_score: f64,
+    /// Authorship of the supplied name-string.
+    authorship_input: String,
+    /// Authorship of the matched name.
+    authorship_match: String,
+    /// Quality of parsing of the matched name, 1 is the best quality.
+    parsing_quality: i64,
+    /// Explanation of the score, if there is one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<String>,
 }
 
 /// Describes a match type of a successful verification attempt.
-#[derive(Debug, Display, Clone)]
+#[derive(Debug, Display, Clone, Default, PartialEq)]
 pub enum MatchType {
     /// Supplied name-string did not match anything in a Data Source.
+    #[default]
     NoMatch,
     /// Exact match of either whole name-string to a record in the Data Source
     /// or exact match of the canonical form.
@@ -97,9 +170,34 @@ pub enum MatchType {
     PartialFuzzy,
 }
 
-impl Default for MatchType {
-    fn default() -> Self {
-        MatchType::NoMatch
+impl MatchType {
+    /// Creates a match type out of a string. Case, underscores and dashes
+    /// are ignored, so "partial_exact" and "PartialExact" are the same.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use gnverify::MatchType;
+    ///
+    /// assert_eq!(MatchType::new("partial_fuzzy").unwrap(), MatchType::PartialFuzzy);
+    /// assert!(MatchType::new("approximate").is_err());
+    /// ```
+    pub fn new(m: &str) -> Result<Self, GNVerifyError> {
+        let normalized: String = m
+            .chars()
+            .filter(|c| *c != '_' && *c != '-')
+            .collect::<String>()
+            .to_lowercase();
+        match normalized.trim() {
+            "exact" => Ok(MatchType::Exact),
+            "fuzzy" => Ok(MatchType::Fuzzy),
+            "partialexact" => Ok(MatchType::PartialExact),
+            "partialfuzzy" => Ok(MatchType::PartialFuzzy),
+            "nomatch" => Ok(MatchType::NoMatch),
+            _ => Err(GNVerifyError::InvalidMatchTypeString {
+                match_type: m.to_owned(),
+            }),
+        }
     }
 }
 
@@ -118,19 +216,14 @@ impl Serialize for MatchType {
     }
 }
 
-#[derive(Debug, Display)]
+#[derive(Debug, Display, Default)]
 pub enum CurationType {
+    #[default]
     NotCurated,
     AutoCurated,
     Curated,
 }
 
-impl Default for CurationType {
-    fn default() -> Self {
-        CurationType::NotCurated
-    }
-}
-
 impl Serialize for CurationType {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -146,12 +239,19 @@ impl Serialize for CurationType {
 
 impl Output {
     /// Creates a new output using verification data returned from gnindex
-    /// server.
-    pub fn new<'b>(item: Verified, retries: i64, preferred_only: bool) -> Self {
+    /// server. The best and preferred results that do not meet acceptance
+    /// criteria are downgraded to NoMatch.
+    pub fn new(
+        item: Verified,
+        retries: i64,
+        preferred_only: bool,
+        acceptance: &Acceptance,
+    ) -> Self {
         let mut best_result: Option<ResultData> = None;
         let mut match_type = MatchType::NoMatch;
-        if item.results.len() > 0 {
-            let best_match = item.results[0].to_result_data();
+        if !item.results.is_empty() {
+            let mut best_match = item.results[0].to_result_data();
+            best_match.accept(acceptance);
             match_type = best_match.match_type.clone();
             if !preferred_only {
                 best_result = Some(best_match);
@@ -160,77 +260,254 @@ impl Output {
         let mut preferred_results: Option<Vec<ResultData>> = None;
         let mut pref_res_tmp: Vec<ResultData> = Vec::with_capacity(item.preferred_results.len());
         for res in item.preferred_results {
-            pref_res_tmp.push(res.to_result_data())
+            let mut pref = res.to_result_data();
+            pref.accept(acceptance);
+            pref_res_tmp.push(pref)
         }
-        if pref_res_tmp.len() > 0 {
+        if !pref_res_tmp.is_empty() {
             preferred_results = Some(pref_res_tmp);
         };
         let curation_str = item.quality_summary.unwrap_or("".to_owned());
 
         Output {
+            id: item.supplied_id,
             name: item.supplied_input.unwrap(),
             match_type,
             data_sources_num: item.matched_data_sources,
             data_source_curation: get_curation(&curation_str),
             retries,
+            wait_ms: 0,
+            only_sources: None,
             error: None,
             best_result,
+            all_results: None,
             preferred_results,
+            fields: None,
         }
     }
 
-    /// Converts output data to a structure for CSV format.
+    /// Keeps all matches of the name-string in all_results. Matches that do
+    /// not meet acceptance criteria are downgraded to NoMatch.
+    pub(crate) fn set_all_results(&mut self, results: &[VerifiedData], acceptance: &Acceptance) {
+        let mut all: Vec<ResultData> = results.iter().map(|r| r.to_result_data()).collect();
+        all.iter_mut().for_each(|r| r.accept(acceptance));
+        self.all_results = Some(all);
+    }
+
+    /// Keeps only vernacular names in given languages. Languages can be
+    /// given by their names or by ISO 639-1 and ISO 639-3 codes of common
+    /// languages. If no languages are given, all names are kept.
+    pub(crate) fn filter_vernaculars(&mut self, languages: &[String]) {
+        if languages.is_empty() {
+            return;
+        }
+        let results = self
+            .best_result
+            .iter_mut()
+            .chain(self.all_results.iter_mut().flatten())
+            .chain(self.preferred_results.iter_mut().flatten());
+        for r in results {
+            if let Some(vernaculars) = r.vernaculars.as_mut() {
+                vernaculars.retain(|v| {
+                    let lang = v.language.as_deref().unwrap_or("");
+                    languages.iter().any(|l| same_language(lang, l))
+                });
+                if vernaculars.is_empty() {
+                    r.vernaculars = None;
+                }
+            }
+        }
+    }
+
+    /// Converts output data to a structure for CSV format. There is a row
+    /// for the best result, a row for every other match (if all matches
+    /// were requested), and a row for every preferred result.
     pub fn to_csv(&self, preferred_only: bool) -> Vec<OutputCSV> {
-        let mut len = 1;
-        if self.preferred_results.is_some() {
-            len += self.preferred_results.as_ref().unwrap().len();
+        let mut res: Vec<OutputCSV> = Vec::new();
+        if !preferred_only || self.preferred_results.is_none() {
+            let kind = if preferred_only {
+                "PreferredMatch"
+            } else {
+                "BestMatch"
+            };
+            res.push(self.csv_row(kind, self.best_result.as_ref()));
         }
-        let mut kind = "BestMatch".to_owned();
-        if preferred_only {
-            kind = "PreferredMatch".to_owned();
+        for r in self.other_matches() {
+            res.push(self.csv_row("Match", Some(r)));
+        }
+        if let Some(pref) = self.preferred_results.as_ref() {
+            for p in pref {
+                res.push(self.csv_row("PreferredMatch", Some(p)));
+            }
         }
-        let mut res: Vec<OutputCSV> = Vec::with_capacity(len);
-        let mut o_csv = OutputCSV {
-            kind,
+        res
+    }
+
+    fn csv_row(&self, kind: &str, result: Option<&ResultData>) -> OutputCSV {
+        let mut row = OutputCSV {
+            id: self.id.clone(),
+            kind: kind.to_owned(),
             scientific_name: self.name.clone(),
             ..Default::default()
         };
-        if let Some(best) = self.best_result.as_ref() {
-            o_csv.matched_name = Some(best.matched_name.clone());
-            o_csv.matched_canonical = best.matched_canonical.clone();
-            o_csv.taxon_id = Some(best.taxon_id.clone());
-            o_csv.current_name = best.current_name.clone();
-            o_csv.edit_distance = Some(best.edit_distance);
-            o_csv.synonym = best.synonym;
-            o_csv.data_source_id = Some(best.data_source_id);
-            o_csv.data_source_title = Some(trim(best.data_source_title.clone()));
-            o_csv.classification_path = best.classification_path.clone();
-            o_csv.match_type = best.match_type.clone();
+        if let Some(r) = result {
+            row.matched_name = Some(r.matched_name.clone());
+            row.matched_canonical = r.matched_canonical.clone();
+            row.taxon_id = Some(r.taxon_id.clone());
+            row.current_name = r.current_name.clone();
+            row.edit_distance = Some(r.edit_distance);
+            row.synonym = r.synonym;
+            row.data_source_id = Some(r.data_source_id);
+            row.data_source_title = Some(trim(r.data_source_title.clone()));
+            row.classification_path = r.classification_path.clone();
+            row.classification_ranks = r.classification_rank.clone();
+            row.classification_ids = r.classification_ids.clone();
+            row.match_type = r.match_type.clone();
+            row.score = r.score.value.map(|s| s.to_string());
+            row.match_type_score = Some(r.score.match_type.to_string());
+            row.name_type_score = r.score.name_type.map(|s| s.to_string());
+            row.author_score = Some(r.score.author_score.to_string());
+            row.authorship_input = Some(r.score.authorship_input.clone());
+            row.authorship_match = Some(r.score.authorship_match.clone());
+            row.parsing_quality = Some(r.score.parsing_quality.to_string());
+            row.score_message = r.score.message.clone();
+            row.reject_reason = r.reject_reason.clone();
+            row.vernacular_names = r.vernaculars.as_ref().map(|vs| {
+                let names: Vec<String> = vs.iter().map(|v| v.to_string()).collect();
+                names.join("|")
+            });
+        }
+        row
+    }
+
+    /// Returns matches from all_results except the best one.
+    fn other_matches(&self) -> impl Iterator<Item = &ResultData> {
+        self.all_results.iter().flat_map(|all| all.iter().skip(1))
+    }
+
+    /// Converts output data to rows with Darwin Core terms. Like with CSV,
+    /// there is a row for the best result, for every other match, and for
+    /// every preferred result. Matches are skipped if they are the same
+    /// records as one of the preferred results.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use gnverify::{GNVerify, Input, Mock};
+    ///
+    /// let mut gnv = GNVerify::new();
+    /// gnv.backend(Mock::from_json(include_str!("../../testdata/verified.json")).unwrap());
+    /// let inputs = vec![Input{id: None, name: "Felis concolor".to_owned(), fields: None}];
+    /// let outputs = gnv.verify(&inputs);
+    /// let rows = outputs[0].to_dwc(false);
+    /// assert_eq!(rows[0].verbatim_scientific_name, "Felis concolor");
+    /// assert_eq!(rows[0].taxonomic_status, Some("synonym".to_owned()));
+    /// assert_eq!(rows[0].accepted_name_usage, Some("Puma concolor (Linnaeus, 1771)".to_owned()));
+    /// ```
+    pub fn to_dwc(&self, preferred_only: bool) -> Vec<OutputDwC> {
+        let mut res: Vec<OutputDwC> = Vec::new();
+        let is_preferred = |r: &ResultData| match &self.preferred_results {
+            Some(pref) => pref
+                .iter()
+                .any(|p| p.data_source_id == r.data_source_id && p.taxon_id == r.taxon_id),
+            None => false,
         };
-        if !preferred_only || self.preferred_results.is_none() {
-            res.push(o_csv);
+        let best_is_preferred = self.best_result.as_ref().is_some_and(is_preferred);
+        if (!preferred_only || self.preferred_results.is_none()) && !best_is_preferred {
+            res.push(self.dwc_row(self.best_result.as_ref()));
+        }
+        for r in self.other_matches().filter(|r| !is_preferred(r)) {
+            res.push(self.dwc_row(Some(r)));
         }
         if let Some(pref) = self.preferred_results.as_ref() {
             for p in pref {
-                let o_csv = OutputCSV {
-                    kind: "PreferredMatch".to_owned(),
-                    scientific_name: self.name.clone(),
-                    matched_name: Some(p.matched_name.clone()),
-                    matched_canonical: p.matched_canonical.clone(),
-                    taxon_id: Some(p.taxon_id.clone()),
-                    current_name: p.current_name.clone(),
-                    edit_distance: Some(p.edit_distance),
-                    synonym: p.synonym,
-                    data_source_id: Some(p.data_source_id),
-                    data_source_title: Some(trim(p.data_source_title.clone())),
-                    classification_path: p.classification_path.clone(),
-                    match_type: p.match_type.clone(),
-                };
-                res.push(o_csv);
+                res.push(self.dwc_row(Some(p)));
             }
         }
         res
     }
+
+    fn dwc_row(&self, result: Option<&ResultData>) -> OutputDwC {
+        let mut row = OutputDwC {
+            id: self.id.clone(),
+            verbatim_scientific_name: self.name.clone(),
+            identification_verification_status: MatchType::NoMatch.to_string(),
+            ..Default::default()
+        };
+        if let Some(r) = result {
+            let status = if r.synonym { "synonym" } else { "accepted" };
+            row.scientific_name = Some(r.matched_name.clone());
+            row.taxon_rank = r
+                .classification_rank
+                .as_ref()
+                .and_then(|ranks| ranks.rsplit('|').next())
+                .filter(|rank| !rank.is_empty())
+                .map(|rank| rank.to_owned());
+            row.accepted_name_usage = r.current_name.clone();
+            row.taxonomic_status = Some(status.to_owned());
+            row.taxon_id = Some(r.taxon_id.clone());
+            row.higher_classification = r.classification_path.clone();
+            row.name_according_to = Some(r.data_source_title.clone());
+            row.dataset_id = Some(r.data_source_id);
+            row.identification_verification_status = r.match_type.to_string();
+        }
+        row
+    }
+}
+
+impl ResultData {
+    /// Downgrades the match to NoMatch if it does not meet acceptance
+    /// criteria.
+    fn accept(&mut self, acceptance: &Acceptance) {
+        if let Err(reason) = acceptance.check(
+            &self.match_type,
+            self.edit_distance,
+            self.stem_edit_distance,
+            self.score.value,
+        ) {
+            self.match_type = MatchType::NoMatch;
+            self.reject_reason = Some(reason);
+        }
+    }
+}
+
+impl fmt::Display for Vernacular {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.language {
+            Some(lang) if !lang.is_empty() => write!(f, "{} ({})", self.name, lang),
+            _ => write!(f, "{}", self.name),
+        }
+    }
+}
+
+/// Names and ISO 639-1 and ISO 639-3 codes of common languages of
+/// vernacular names.
+const LANGUAGES: [[&str; 3]; 12] = [
+    ["english", "en", "eng"],
+    ["spanish", "es", "spa"],
+    ["french", "fr", "fra"],
+    ["german", "de", "deu"],
+    ["portuguese", "pt", "por"],
+    ["italian", "it", "ita"],
+    ["dutch", "nl", "nld"],
+    ["russian", "ru", "rus"],
+    ["chinese", "zh", "zho"],
+    ["japanese", "ja", "jpn"],
+    ["arabic", "ar", "ara"],
+    ["hindi", "hi", "hin"],
+];
+
+/// Returns true if a language of a vernacular name is the same as the
+/// language given by a user, either by name or by code.
+fn same_language(language: &str, filter: &str) -> bool {
+    let language = language.trim().to_lowercase();
+    let filter = filter.trim().to_lowercase();
+    if language == filter {
+        return true;
+    }
+    LANGUAGES
+        .iter()
+        .any(|l| l.contains(&language.as_str()) && l.contains(&filter.as_str()))
 }
 
 fn trim(s: String) -> String {
@@ -252,14 +529,24 @@ fn get_curation(cur: &str) -> Option<CurationType> {
 
 impl ToResultData for VerifiedData {
     fn to_result_data(&self) -> ResultData {
-        let matched_canonical = match &self.canonical_name {
-            None => None,
-            Some(can) => Some(can.value_ranked.to_owned()),
-        };
-        let current_name = match &self.accepted_name {
-            None => None,
-            Some(acc_name) => Some(acc_name.name.value.to_owned()),
-        };
+        let matched_canonical = self
+            .canonical_name
+            .as_ref()
+            .map(|can| can.value_ranked.to_owned());
+        let current_name = self
+            .accepted_name
+            .as_ref()
+            .map(|acc_name| acc_name.name.value.to_owned());
+        let vernaculars: Vec<Vernacular> = self
+            .vernaculars
+            .iter()
+            .map(|v| Vernacular {
+                name: v.name.to_owned(),
+                language: v.language.to_owned(),
+                locality: v.locality.to_owned(),
+                country_code: v.country_code.to_owned(),
+            })
+            .collect();
         ResultData {
             data_source_title: self.data_source.title.to_owned(),
             data_source_id: self.data_source.id,
@@ -274,21 +561,42 @@ impl ToResultData for VerifiedData {
             stem_edit_distance: self.match_type.stem_edit_distance.unwrap_or(0),
             match_type: get_match_type(&self.match_type.kind),
             synonym: self.synonym,
-            ..Default::default()
+            score: Score {
+                value: self.score.value,
+                match_type: self.match_type.score,
+                name_type: self.score.name_type,
+                author_score: self.score.author_score.value,
+                authorship_input: self.score.author_score.authorship_input.to_owned(),
+                authorship_match: self.score.author_score.authorship_match.to_owned(),
+                parsing_quality: self.score.parsing_quality,
+                message: self.score.message.to_owned(),
+            },
+            reject_reason: None,
+            vernaculars: Some(vernaculars).filter(|vs| !vs.is_empty()),
         }
     }
 }
 
 impl ToResultData for VerifiedPreferredData {
     fn to_result_data(&self) -> ResultData {
-        let matched_canonical = match &self.canonical_name {
-            None => None,
-            Some(can) => Some(can.value_ranked.to_owned()),
-        };
-        let current_name = match &self.accepted_name {
-            None => None,
-            Some(acc_name) => Some(acc_name.name.value.to_owned()),
-        };
+        let matched_canonical = self
+            .canonical_name
+            .as_ref()
+            .map(|can| can.value_ranked.to_owned());
+        let current_name = self
+            .accepted_name
+            .as_ref()
+            .map(|acc_name| acc_name.name.value.to_owned());
+        let vernaculars: Vec<Vernacular> = self
+            .vernaculars
+            .iter()
+            .map(|v| Vernacular {
+                name: v.name.to_owned(),
+                language: v.language.to_owned(),
+                locality: v.locality.to_owned(),
+                country_code: v.country_code.to_owned(),
+            })
+            .collect();
         ResultData {
             data_source_title: self.data_source.title.to_owned(),
             data_source_id: self.data_source.id,
@@ -303,7 +611,18 @@ impl ToResultData for VerifiedPreferredData {
             stem_edit_distance: self.match_type.stem_edit_distance.unwrap_or(0),
             match_type: get_match_type(&self.match_type.kind),
             synonym: self.synonym,
-            ..Default::default()
+            score: Score {
+                value: self.score.value,
+                match_type: self.match_type.score,
+                name_type: self.score.name_type,
+                author_score: self.score.author_score.value,
+                authorship_input: self.score.author_score.authorship_input.to_owned(),
+                authorship_match: self.score.author_score.authorship_match.to_owned(),
+                parsing_quality: self.score.parsing_quality,
+                message: self.score.message.to_owned(),
+            },
+            reject_reason: None,
+            vernaculars: Some(vernaculars).filter(|vs| !vs.is_empty()),
         }
     }
 }
@@ -321,3 +640,31 @@ fn get_match_type(match_type: &str) -> MatchType {
 fn is_zero(i: &i64) -> bool {
     *i == 0
 }
+
+#[test]
+fn score_in_outputs() {
+    let mock = super::Mock::from_json(include_str!("../../testdata/verified.json")).unwrap();
+    let mut gnv = crate::GNVerify::new();
+    gnv.backend(mock);
+    gnv.all_matches();
+    let inputs = vec![super::Input {
+        name: "Bubo bubbo".to_owned(),
+        ..Default::default()
+    }];
+    let outputs = gnv.verify(&inputs);
+    let rows = outputs[0].to_csv(false);
+    assert_eq!(rows[0].match_type_score, Some("1".to_owned()));
+    assert_eq!(rows[0].parsing_quality, Some("1".to_owned()));
+    let score = |i: usize| rows[i].score.as_ref().unwrap().parse::<f64>().unwrap();
+    assert!(score(0) > score(1));
+    assert_eq!(
+        rows[0].authorship_match,
+        Some("(Linnaeus, 1758)".to_owned())
+    );
+    let json = serde_json::to_value(&outputs[0]).unwrap();
+    assert_eq!(json["bestResult"]["score"]["matchType"], 1);
+    assert!(rows[0]
+        .headers()
+        .unwrap()
+        .contains(&"MatchTypeScore".to_owned()));
+}
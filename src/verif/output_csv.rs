@@ -1,9 +1,12 @@
 use super::MatchType;
+use anyhow::{Context, Result};
 use serde::Serialize;
 
 #[derive(Debug, Default, Serialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct OutputCSV {
+    #[serde(rename = "ID", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
     pub kind: String,
     pub match_type: MatchType,
     pub edit_distance: Option<i64>,
@@ -16,4 +19,137 @@ pub struct OutputCSV {
     pub data_source_id: Option<i64>,
     pub data_source_title: Option<String>,
     pub classification_path: Option<String>,
+    pub classification_ranks: Option<String>,
+    #[serde(rename = "ClassificationIDs")]
+    pub classification_ids: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub match_type_score: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_type_score: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_score: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorship_input: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorship_match: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parsing_quality: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score_message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reject_reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vernacular_names: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kingdom: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phylum: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub family: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub genus: Option<String>,
+}
+
+impl OutputCSV {
+    /// Returns CSV headers for the row. Optional fields that are not set
+    /// for the row are not included.
+    pub fn headers(&self) -> Result<Vec<String>> {
+        headers(self)
+    }
+
+    /// Keeps Score, MatchTypeScore, NameTypeScore, AuthorScore,
+    /// AuthorshipInput, AuthorshipMatch, ParsingQuality, and ScoreMessage
+    /// columns if on is true, otherwise removes them from the row.
+    pub(crate) fn set_score_columns(&mut self, on: bool) {
+        for column in [
+            &mut self.score,
+            &mut self.match_type_score,
+            &mut self.name_type_score,
+            &mut self.author_score,
+            &mut self.authorship_input,
+            &mut self.authorship_match,
+            &mut self.parsing_quality,
+            &mut self.score_message,
+        ] {
+            set_column(column, on);
+        }
+    }
+
+    /// Fills Kingdom, Phylum, Class, Order, Family, and Genus columns from
+    /// the classification path and its ranks. Columns of ranks that are
+    /// not in the classification are set to empty strings, so all rows
+    /// have the same columns.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use gnverify::{GNVerify, Input, Mock};
+    ///
+    /// let mut gnv = GNVerify::new();
+    /// gnv.backend(Mock::from_json(include_str!("../../testdata/verified.json")).unwrap());
+    /// let inputs = vec![Input{id: None, name: "Bubo bubbo".to_owned(), fields: None}];
+    /// let outputs = gnv.verify(&inputs);
+    /// let mut row = outputs[0].to_csv(false).remove(0);
+    /// row.add_rank_columns();
+    /// assert_eq!(row.class, Some("Aves".to_owned()));
+    /// assert_eq!(row.genus, Some("Bubo".to_owned()));
+    /// ```
+    pub fn add_rank_columns(&mut self) {
+        let mut columns: [Option<String>; 6] = Default::default();
+        if let (Some(path), Some(ranks)) = (&self.classification_path, &self.classification_ranks) {
+            for (name, rank) in path.split('|').zip(ranks.split('|')) {
+                if let Some(i) = RANKS
+                    .iter()
+                    .position(|r| r.eq_ignore_ascii_case(rank.trim()))
+                {
+                    columns[i] = Some(name.trim().to_owned());
+                }
+            }
+        }
+        let [kingdom, phylum, class, order, family, genus] =
+            columns.map(|c| Some(c.unwrap_or_default()));
+        self.kingdom = kingdom;
+        self.phylum = phylum;
+        self.class = class;
+        self.order = order;
+        self.family = family;
+        self.genus = genus;
+    }
+}
+
+/// Turns an optional column on or off. A column that is on always has a
+/// value, an empty string if there is nothing else, so all rows have the same
+/// columns.
+pub(crate) fn set_column(value: &mut Option<String>, on: bool) {
+    *value = if on {
+        Some(value.take().unwrap_or_default())
+    } else {
+        None
+    };
+}
+
+/// Ranks that can be added to CSV output as separate columns.
+const RANKS: [&str; 6] = ["kingdom", "phylum", "class", "order", "family", "genus"];
+
+/// Returns CSV headers of a serializable row.
+pub(crate) fn headers<T: Serialize>(row: &T) -> Result<Vec<String>> {
+    let mut wtr = csv::WriterBuilder::new()
+        .has_headers(true)
+        .from_writer(Vec::new());
+    wtr.serialize(row)?;
+    let data = wtr.into_inner()?;
+    let mut rdr = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .from_reader(&data[..]);
+    let headers = rdr
+        .records()
+        .next()
+        .context("cannot create CSV headers")??;
+    Ok(headers.iter().map(|h| h.to_owned()).collect())
 }
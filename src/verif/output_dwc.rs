@@ -0,0 +1,42 @@
+use anyhow::Result;
+use serde::Serialize;
+
+/// A row of verification results where fields are named by Darwin Core
+/// terms.
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutputDwC {
+    /// ID supplied by user together with the name-string.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Name-string supplied by user for verification.
+    pub verbatim_scientific_name: String,
+    /// Name that matched the name-string.
+    pub scientific_name: Option<String>,
+    /// Rank of the matched name, if it is known from the classification.
+    pub taxon_rank: Option<String>,
+    /// Currently accepted name for the matched taxon.
+    pub accepted_name_usage: Option<String>,
+    /// "accepted" or "synonym".
+    pub taxonomic_status: Option<String>,
+    /// ID of the matched record in the Data Source.
+    #[serde(rename = "taxonID")]
+    pub taxon_id: Option<String>,
+    /// Classification path of the matched taxon.
+    pub higher_classification: Option<String>,
+    /// Title of the Data Source of the match.
+    pub name_according_to: Option<String>,
+    /// ID of the Data Source of the match in gnindex.
+    #[serde(rename = "datasetID")]
+    pub dataset_id: Option<i64>,
+    /// Match type of the result (Exact, Fuzzy, NoMatch etc.).
+    pub identification_verification_status: String,
+}
+
+impl OutputDwC {
+    /// Returns headers for the row. The id field is not included if it is
+    /// not set.
+    pub fn headers(&self) -> Result<Vec<String>> {
+        super::output_csv::headers(self)
+    }
+}